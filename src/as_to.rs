@@ -0,0 +1,28 @@
+/// Extension trait providing the [`as_to`](AsTo::as_to) and
+/// [`as_mut_to`](AsTo::as_mut_to) methods.
+pub trait AsTo {
+    /// Borrows as `&U` by calling `AsRef<U>::as_ref`.
+    #[inline(always)]
+    fn as_to<U>(&self) -> &U
+    where
+        Self: AsRef<U>,
+        U: ?Sized,
+    {
+        <Self as AsRef<U>>::as_ref(self)
+    }
+
+    /// Borrows as `&mut U` by calling `AsMut<U>::as_mut`.
+    #[inline(always)]
+    fn as_mut_to<U>(&mut self) -> &mut U
+    where
+        Self: AsMut<U>,
+        U: ?Sized,
+    {
+        <Self as AsMut<U>>::as_mut(self)
+    }
+}
+
+/// Blanket impl for all types, including unsized ones like `str` and `[T]`.
+/// This makes sure that everything implements `AsTo` and
+/// that no downstream impls can exist.
+impl<T: ?Sized> AsTo for T {}
@@ -0,0 +1,33 @@
+use core::borrow::{Borrow, BorrowMut};
+
+/// Extension trait providing the [`borrow_to`](BorrowTo::borrow_to) and
+/// [`borrow_mut_to`](BorrowTo::borrow_mut_to) methods.
+///
+/// The distinct method names avoid clashes with inherent methods like
+/// `RefCell::borrow`.
+pub trait BorrowTo {
+    /// Borrows as `&B` by calling `Borrow<B>::borrow`.
+    #[inline(always)]
+    fn borrow_to<B>(&self) -> &B
+    where
+        Self: Borrow<B>,
+        B: ?Sized,
+    {
+        <Self as Borrow<B>>::borrow(self)
+    }
+
+    /// Borrows as `&mut B` by calling `BorrowMut<B>::borrow_mut`.
+    #[inline(always)]
+    fn borrow_mut_to<B>(&mut self) -> &mut B
+    where
+        Self: BorrowMut<B>,
+        B: ?Sized,
+    {
+        <Self as BorrowMut<B>>::borrow_mut(self)
+    }
+}
+
+/// Blanket impl for all types.
+/// This makes sure that everything implements `BorrowTo` and
+/// that no downstream impls can exist.
+impl<T: ?Sized> BorrowTo for T {}
@@ -0,0 +1,95 @@
+use core::convert::TryInto;
+
+/// Extension trait providing conversion combinators on `Option`.
+pub trait OptionTo<T> {
+    /// Converts the contained value to `U` by calling `Into<U>::into`.
+    fn map_to<U>(self) -> Option<U>
+    where
+        T: Into<U>;
+
+    /// Tries to convert the contained value to `U` by calling `TryInto<U>::try_into`.
+    ///
+    /// `None` stays `None` and never fails.
+    fn try_map_to<U>(self) -> Result<Option<U>, <T as TryInto<U>>::Error>
+    where
+        T: TryInto<U>;
+}
+
+impl<T> OptionTo<T> for Option<T> {
+    #[inline(always)]
+    fn map_to<U>(self) -> Option<U>
+    where
+        T: Into<U>,
+    {
+        self.map(Into::into)
+    }
+
+    #[inline(always)]
+    fn try_map_to<U>(self) -> Result<Option<U>, <T as TryInto<U>>::Error>
+    where
+        T: TryInto<U>,
+    {
+        self.map(TryInto::try_into).transpose()
+    }
+}
+
+/// Extension trait providing conversion combinators on `Result`.
+pub trait ResultTo<T, E> {
+    /// Converts the `Ok` value to `U` by calling `Into<U>::into`.
+    fn map_to<U>(self) -> Result<U, E>
+    where
+        T: Into<U>;
+
+    /// Converts the `Err` value to `F` by calling `Into<F>::into`.
+    fn map_err_to<F>(self) -> Result<T, F>
+    where
+        E: Into<F>;
+
+    /// Discards the error and converts the `Ok` value to `U` by calling `Into<U>::into`.
+    fn ok_to<U>(self) -> Option<U>
+    where
+        T: Into<U>;
+
+    /// Tries to convert the `Ok` value to `U` by calling `TryInto<U>::try_into`.
+    ///
+    /// A conversion error is converted into `E` by calling `Into<E>::into`.
+    fn and_try_to<U>(self) -> Result<U, E>
+    where
+        T: TryInto<U>,
+        <T as TryInto<U>>::Error: Into<E>;
+}
+
+impl<T, E> ResultTo<T, E> for Result<T, E> {
+    #[inline(always)]
+    fn map_to<U>(self) -> Result<U, E>
+    where
+        T: Into<U>,
+    {
+        self.map(Into::into)
+    }
+
+    #[inline(always)]
+    fn map_err_to<F>(self) -> Result<T, F>
+    where
+        E: Into<F>,
+    {
+        self.map_err(Into::into)
+    }
+
+    #[inline(always)]
+    fn ok_to<U>(self) -> Option<U>
+    where
+        T: Into<U>,
+    {
+        self.ok().map(Into::into)
+    }
+
+    #[inline(always)]
+    fn and_try_to<U>(self) -> Result<U, E>
+    where
+        T: TryInto<U>,
+        <T as TryInto<U>>::Error: Into<E>,
+    {
+        self.and_then(|value| value.try_into().map_err(Into::into))
+    }
+}
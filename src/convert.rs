@@ -0,0 +1,99 @@
+/// Conversion owned by this crate, so that downstream crates can declare
+/// conversions between two foreign types.
+///
+/// The orphan rules forbid `impl From<A> for B` when both `A` and `B` are foreign.
+/// They do allow `impl ConvertFrom<A, M> for B` as long as the marker type `M` is
+/// local, which is what [`impl_convert_from!`](crate::impl_convert_from) generates.
+///
+/// Every `From` impl is also available through the [`ViaFrom`] marker.
+pub trait ConvertFrom<T, M>: Sized {
+    /// Converts `value`.
+    fn convert_from(value: T) -> Self;
+}
+
+/// Marker for the blanket impl of [`ConvertFrom`] backed by `From`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ViaFrom {}
+
+impl<T, U: From<T>> ConvertFrom<T, ViaFrom> for U {
+    #[inline(always)]
+    fn convert_from(value: T) -> Self {
+        U::from(value)
+    }
+}
+
+/// Extension trait providing the [`convert_to`](ConvertTo::convert_to) method.
+///
+/// The marker type `M` is inferred, so `convert_to` picks up `From` impls and
+/// conversions declared with [`impl_convert_from!`](crate::impl_convert_from) alike.
+///
+/// Inference fails with "type annotations needed" when more than one marker
+/// provides the conversion, such as a `From` impl and a declared conversion
+/// between the same types. [`convert_to_via`](crate::To::convert_to_via) takes
+/// the marker explicitly:
+///
+/// ```
+/// use to_method::{To as _, ViaFrom};
+///
+/// enum Doubled {}
+///
+/// to_method::impl_convert_from! {
+///     via Doubled;
+///
+///     fn(value: u8) -> u16 {
+///         u16::from(value) * 2
+///     }
+/// }
+///
+/// // `5_u8.convert_to::<u16>()` would be ambiguous here.
+/// assert_eq!(5_u8.convert_to_via::<u16, ViaFrom>(), 5);
+/// assert_eq!(5_u8.convert_to_via::<u16, Doubled>(), 10);
+/// ```
+pub trait ConvertTo<M> {
+    /// Converts to `T` by calling `ConvertFrom<Self, M>::convert_from`.
+    #[inline(always)]
+    fn convert_to<T>(self) -> T
+    where
+        Self: Sized,
+        T: ConvertFrom<Self, M>,
+    {
+        T::convert_from(self)
+    }
+}
+
+/// Blanket impl for all types and markers.
+/// This makes sure that everything implements `ConvertTo` and
+/// that no downstream impls can exist.
+impl<T: ?Sized, M> ConvertTo<M> for T {}
+
+/// Declares [`ConvertFrom`] impls through a local marker type.
+///
+/// ```
+/// use std::time::Duration;
+/// use to_method::ConvertTo as _;
+///
+/// enum Conversions {}
+///
+/// to_method::impl_convert_from! {
+///     via Conversions;
+///
+///     fn(duration: Duration) -> f64 {
+///         duration.as_secs_f64()
+///     }
+/// }
+///
+/// assert_eq!(Duration::from_millis(1500).convert_to::<f64>(), 1.5);
+///
+/// // Plain `From` impls keep working.
+/// assert_eq!(5_u8.convert_to::<u16>(), 5);
+/// ```
+#[macro_export]
+macro_rules! impl_convert_from {
+    (via $marker:ty; $(fn($arg:ident: $src:ty) -> $dst:ty $body:block)*) => {
+        $(
+            impl $crate::ConvertFrom<$src, $marker> for $dst {
+                fn convert_from($arg: $src) -> Self $body
+            }
+        )*
+    };
+}
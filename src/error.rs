@@ -0,0 +1,229 @@
+use core::any;
+use core::fmt;
+
+/// The error type returned by [`try_to_explained`](crate::To::try_to_explained).
+///
+/// Keeps the rejected value and the original conversion error, and names the
+/// source and target types in its `Display` output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionError<S, E> {
+    value: S,
+    error: E,
+    target: &'static str,
+}
+
+impl<S, E> ConversionError<S, E> {
+    pub(crate) fn new<T>(value: S, error: E) -> Self {
+        ConversionError {
+            value,
+            error,
+            target: any::type_name::<T>(),
+        }
+    }
+
+    /// Returns the value that failed to convert.
+    pub fn value(&self) -> &S {
+        &self.value
+    }
+
+    /// Returns the error returned by the conversion.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the name of the source type, as given by [`type_name`](core::any::type_name).
+    pub fn source_type(&self) -> &'static str {
+        any::type_name::<S>()
+    }
+
+    /// Returns the name of the target type, as given by [`type_name`](core::any::type_name).
+    pub fn target_type(&self) -> &'static str {
+        self.target
+    }
+
+    /// Consumes the error, returning the value that failed to convert and the original error.
+    pub fn into_parts(self) -> (S, E) {
+        (self.value, self.error)
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> ConversionError<S, E> {
+    /// Panics with `msg` followed by a description of the failed conversion.
+    #[cold]
+    #[track_caller]
+    pub(crate) fn panic(&self, msg: &str) -> ! {
+        panic!(
+            "{}: cannot convert {:?} from `{}` to `{}`: {:?}",
+            msg,
+            self.value,
+            self.source_type(),
+            self.target,
+            self.error
+        )
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Display> fmt::Display for ConversionError<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot convert {:?} from `{}` to `{}`: {}",
+            self.value,
+            self.source_type(),
+            self.target,
+            self.error
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: fmt::Debug, E: std::error::Error + 'static> std::error::Error for ConversionError<S, E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The error type returned by [`try_to_named`](crate::To::try_to_named).
+///
+/// Names the source and target types in its `Display` output, but unlike
+/// [`ConversionError`] doesn't keep the rejected value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NamedError<E> {
+    error: E,
+    source: &'static str,
+    target: &'static str,
+}
+
+impl<E> NamedError<E> {
+    pub(crate) fn new<S: ?Sized, T>(error: E) -> Self {
+        NamedError {
+            error,
+            source: any::type_name::<S>(),
+            target: any::type_name::<T>(),
+        }
+    }
+
+    /// Returns the error returned by the conversion.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the name of the source type, as given by [`type_name`](core::any::type_name).
+    pub fn source_type(&self) -> &'static str {
+        self.source
+    }
+
+    /// Returns the name of the target type, as given by [`type_name`](core::any::type_name).
+    pub fn target_type(&self) -> &'static str {
+        self.target
+    }
+
+    /// Consumes the error, returning the original error.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Debug> NamedError<E> {
+    /// Panics with `msg` followed by a description of the failed conversion.
+    #[cold]
+    #[track_caller]
+    pub(crate) fn panic(&self, msg: &str) -> ! {
+        panic!(
+            "{}: cannot convert from `{}` to `{}`: {:?}",
+            msg, self.source, self.target, self.error
+        )
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for NamedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot convert from `{}` to `{}`: {}",
+            self.source, self.target, self.error
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for NamedError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The error type returned by [`try_to_via`](crate::To::try_to_via), saying which
+/// of the two conversions failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ViaError<A, B> {
+    /// The conversion from the source type to the intermediate type failed.
+    First(A),
+    /// The conversion from the intermediate type to the target type failed.
+    Second(B),
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for ViaError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViaError::First(err) => write!(f, "conversion to intermediate type failed: {}", err),
+            ViaError::Second(err) => write!(f, "conversion from intermediate type failed: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> std::error::Error for ViaError<A, B>
+where
+    A: std::error::Error + 'static,
+    B: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ViaError::First(err) => Some(err),
+            ViaError::Second(err) => Some(err),
+        }
+    }
+}
+
+/// The error type returned by `TryFrom` impls generated by the `ConvertFrom` derive macro.
+///
+/// Names the field that failed to convert.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldError<E> {
+    /// The name of the field that failed to convert.
+    pub field: &'static str,
+    /// The error returned by the conversion.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for FieldError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field `{}`: {}", self.field, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for FieldError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The error type returned by `TryFrom` impls generated by the `TryFromRepr` derive macro.
+///
+/// Holds the integer that doesn't match the discriminant of any variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvalidDiscriminant<R> {
+    /// The rejected integer.
+    pub value: R,
+}
+
+impl<R: fmt::Display> fmt::Display for InvalidDiscriminant<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no enum variant has the discriminant {}", self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: fmt::Debug + fmt::Display> std::error::Error for InvalidDiscriminant<R> {}
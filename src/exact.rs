@@ -0,0 +1,180 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::float::{FitsIn, Float};
+
+/// The error type returned when a value can't be converted without loss.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExactError {
+    /// The value was NaN, which no integer type can represent.
+    NaN,
+    /// The value was infinite, which no integer type can represent.
+    Infinite,
+    /// The value lies outside the range of the target type.
+    OutOfRange,
+    /// The value lies within the range of the target type, but would lose precision.
+    Inexact,
+}
+
+impl fmt::Display for ExactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ExactError::NaN => "cannot convert NaN to an integer",
+            ExactError::Infinite => "cannot convert an infinite value to an integer",
+            ExactError::OutOfRange => "value out of range of the target type",
+            ExactError::Inexact => "value not exactly representable in the target type",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExactError {}
+
+/// Conversion that only succeeds if the value round-trips without loss.
+///
+/// Implemented for every pair of primitive numeric types.
+///
+/// Integers convert to floats only if they survive the round trip, even when the
+/// nearest float lies just outside the range of the source type:
+///
+/// ```
+/// use to_method::{ExactError, TryFromExact};
+///
+/// assert_eq!(f64::try_from_exact(1_u64 << 53), Ok(9_007_199_254_740_992.0));
+/// assert_eq!(f64::try_from_exact((1_u64 << 53) + 1), Err(ExactError::Inexact));
+/// assert_eq!(f64::try_from_exact((1_u64 << 53) + 2), Ok(9_007_199_254_740_994.0));
+/// assert_eq!(f64::try_from_exact(i64::MAX), Err(ExactError::Inexact));
+/// assert_eq!(f64::try_from_exact(i64::MIN), Ok(-9_223_372_036_854_775_808.0));
+/// assert_eq!(f64::try_from_exact(u64::MAX), Err(ExactError::Inexact));
+/// assert_eq!(f32::try_from_exact(u128::MAX), Err(ExactError::OutOfRange));
+/// assert_eq!(f32::try_from_exact(1_u128 << 127), Ok(1.7014118e38));
+/// ```
+///
+/// Floats convert to integers only if they are integral and in range:
+///
+/// ```
+/// use to_method::{ExactError, TryFromExact};
+///
+/// assert_eq!(i64::try_from_exact(-0.0_f64), Ok(0));
+/// assert_eq!(i64::try_from_exact(0.5_f64), Err(ExactError::Inexact));
+/// assert_eq!(i64::try_from_exact(-9_223_372_036_854_775_808.0_f64), Ok(i64::MIN));
+/// assert_eq!(i64::try_from_exact(9_223_372_036_854_775_808.0_f64), Err(ExactError::OutOfRange));
+/// assert_eq!(u8::try_from_exact(f64::NAN), Err(ExactError::NaN));
+/// assert_eq!(u8::try_from_exact(f64::NEG_INFINITY), Err(ExactError::Infinite));
+/// ```
+///
+/// `f64` converts to `f32` only if the value is representable, including subnormals,
+/// infinities and NaN:
+///
+/// ```
+/// use to_method::{ExactError, TryFromExact};
+///
+/// let min_subnormal = f32::from_bits(1);
+/// assert_eq!(f32::try_from_exact(f64::from(min_subnormal)), Ok(min_subnormal));
+/// assert_eq!(f32::try_from_exact(f64::from(min_subnormal) / 2.0), Err(ExactError::Inexact));
+/// assert_eq!(f32::try_from_exact(f64::from(f32::MAX)), Ok(f32::MAX));
+/// assert_eq!(f32::try_from_exact(f64::MAX), Err(ExactError::OutOfRange));
+/// assert_eq!(f32::try_from_exact(f64::INFINITY), Ok(f32::INFINITY));
+/// assert!(f32::try_from_exact(f64::NAN).unwrap().is_nan());
+/// ```
+pub trait TryFromExact<T>: Sized {
+    /// Converts `value`, failing if the result wouldn't be exactly equal to it.
+    fn try_from_exact(value: T) -> Result<Self, ExactError>;
+}
+
+macro_rules! impl_exact_int {
+    ($($src:ty),*) => {
+        $(
+            impl_exact_int!(@ $src => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        )*
+    };
+    (@ $src:ty => $($dst:ty),*) => {
+        $(
+            impl TryFromExact<$src> for $dst {
+                #[inline]
+                fn try_from_exact(value: $src) -> Result<Self, ExactError> {
+                    <$dst>::try_from(value).map_err(|_| ExactError::OutOfRange)
+                }
+            }
+        )*
+    };
+}
+
+impl_exact_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_exact_float_int {
+    ($($float:ty),*) => {
+        $(
+            impl_exact_float_int!(@ $float => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        )*
+    };
+    (@ $float:ty => $($int:ty),*) => {
+        $(
+            impl TryFromExact<$float> for $int {
+                fn try_from_exact(value: $float) -> Result<Self, ExactError> {
+                    if value.is_nan() {
+                        Err(ExactError::NaN)
+                    } else if value.is_infinite() {
+                        Err(ExactError::Infinite)
+                    } else if value.truncate() != value {
+                        Err(ExactError::Inexact)
+                    } else if FitsIn::<$int>::fits_in(value) {
+                        Ok(value as $int)
+                    } else {
+                        Err(ExactError::OutOfRange)
+                    }
+                }
+            }
+
+            impl TryFromExact<$int> for $float {
+                fn try_from_exact(value: $int) -> Result<Self, ExactError> {
+                    let converted = value as $float;
+                    if converted.is_infinite() {
+                        return Err(ExactError::OutOfRange);
+                    }
+                    // The cast back is range-checked, so saturation can't fake a round trip.
+                    match <$int>::try_from_exact(converted) {
+                        Ok(back) if back == value => Ok(converted),
+                        _ => Err(ExactError::Inexact),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_exact_float_int!(f32, f64);
+
+impl TryFromExact<f32> for f32 {
+    #[inline]
+    fn try_from_exact(value: f32) -> Result<Self, ExactError> {
+        Ok(value)
+    }
+}
+
+impl TryFromExact<f64> for f64 {
+    #[inline]
+    fn try_from_exact(value: f64) -> Result<Self, ExactError> {
+        Ok(value)
+    }
+}
+
+impl TryFromExact<f32> for f64 {
+    #[inline]
+    fn try_from_exact(value: f32) -> Result<Self, ExactError> {
+        Ok(value.into())
+    }
+}
+
+impl TryFromExact<f64> for f32 {
+    fn try_from_exact(value: f64) -> Result<Self, ExactError> {
+        let converted = value as f32;
+        if value.is_nan() || f64::from(converted) == value {
+            Ok(converted)
+        } else if converted.is_infinite() {
+            Err(ExactError::OutOfRange)
+        } else {
+            Err(ExactError::Inexact)
+        }
+    }
+}
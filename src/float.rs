@@ -0,0 +1,75 @@
+//! Float helpers that work without `std` or `libm`.
+
+use crate::Rounding;
+
+pub(crate) trait Float: Copy {
+    /// Rounds toward zero.
+    fn truncate(self) -> Self;
+
+    /// Rounds to an integral value using `mode`.
+    ///
+    /// The result is unspecified for NaN and infinities.
+    fn round_with(self, mode: Rounding) -> Self;
+}
+
+/// Range check for integral float values.
+pub(crate) trait FitsIn<I> {
+    /// Returns whether `self`, which must be integral, lies within the bounds of `I`.
+    fn fits_in(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ($($float:ty => $int:ty, $exact:expr;)*) => {
+        $(
+            impl Float for $float {
+                #[inline]
+                fn truncate(self) -> Self {
+                    // Values at or beyond `$exact` have no fractional part.
+                    if self > -$exact && self < $exact {
+                        (self as $int) as $float
+                    } else {
+                        self
+                    }
+                }
+
+                fn round_with(self, mode: Rounding) -> Self {
+                    let trunc = self.truncate();
+                    let fract = self - trunc;
+                    let distance = if fract < 0.0 { -fract } else { fract };
+                    let is_odd = (trunc / 2.0).truncate() * 2.0 != trunc;
+                    let away = match mode {
+                        Rounding::TowardZero => false,
+                        Rounding::Floor => fract < 0.0,
+                        Rounding::Ceil => fract > 0.0,
+                        Rounding::NearestTiesAway => distance >= 0.5,
+                        Rounding::NearestTiesEven => distance > 0.5 || (distance == 0.5 && is_odd),
+                    };
+                    match (away, fract < 0.0) {
+                        (false, _) => trunc,
+                        (true, false) => trunc + 1.0,
+                        (true, true) => trunc - 1.0,
+                    }
+                }
+            }
+
+            impl_float!(@ $float => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        )*
+    };
+    (@ $float:ty => $($int:ty),*) => {
+        $(
+            impl FitsIn<$int> for $float {
+                #[inline]
+                fn fits_in(self) -> bool {
+                    // `MIN` is always exactly representable, `MAX` may round up
+                    // to the next power of two, which itself is out of range.
+                    self >= <$int>::MIN as $float && self - 1.0 < <$int>::MAX as $float
+                }
+            }
+        )*
+    };
+}
+
+impl_float! {
+    f32 => i32, 8_388_608.0;
+    f64 => i64, 4_503_599_627_370_496.0;
+}
@@ -0,0 +1,200 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+use core::marker::PhantomData;
+
+use crate::To;
+
+/// An error from converting one element of a sequence, along with the element's index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndexedError<E> {
+    /// The index of the element that failed to convert.
+    pub index: usize,
+    /// The error returned by the conversion.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for IndexedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "element {}: {}", self.index, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for IndexedError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Extension trait providing the [`map_to`](IteratorTo::map_to) and
+/// [`try_map_to`](IteratorTo::try_map_to) iterator adapters, as well as the
+/// [`try_collect_to`](IteratorTo::try_collect_to) and
+/// [`try_to_all`](IteratorTo::try_to_all) methods.
+pub trait IteratorTo: Iterator + Sized {
+    /// Converts each item to `T` by calling `Into<T>::into`.
+    #[inline(always)]
+    fn map_to<T>(self) -> MapTo<Self, T>
+    where
+        Self::Item: Into<T>,
+    {
+        MapTo {
+            iter: self,
+            target: PhantomData,
+        }
+    }
+
+    /// Tries to convert each item to `T` by calling `TryInto<T>::try_into`.
+    #[inline(always)]
+    fn try_map_to<T>(self) -> TryMapTo<Self, T>
+    where
+        Self::Item: TryInto<T>,
+    {
+        TryMapTo {
+            iter: self,
+            target: PhantomData,
+        }
+    }
+
+    /// Tries to convert each item to the item type of `C` by calling [`To::try_to`] and
+    /// collects the results into `C`.
+    ///
+    /// Stops at the first item that fails to convert and returns its error along with its index.
+    ///
+    /// `C` names its item type through `IntoIterator`, which every standard collection
+    /// except `String` implements.
+    #[allow(clippy::type_complexity)]
+    fn try_collect_to<C>(self) -> Result<C, IndexedError<<Self::Item as TryInto<C::Item>>::Error>>
+    where
+        Self::Item: TryInto<C::Item>,
+        C: IntoIterator + FromIterator<C::Item>,
+    {
+        self.enumerate()
+            .map(|(index, item)| {
+                item.try_to::<C::Item>()
+                    .map_err(|error| IndexedError { index, error })
+            })
+            .collect()
+    }
+
+    /// Tries to convert each item to the item type of `C` by calling [`To::try_to`] and
+    /// collects the results into `C`.
+    ///
+    /// Unlike [`try_collect_to`](IteratorTo::try_collect_to), this doesn't stop at
+    /// the first failure, but returns the errors of all items that failed to convert.
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    fn try_to_all<C>(self) -> Result<C, Vec<IndexedError<<Self::Item as TryInto<C::Item>>::Error>>>
+    where
+        Self::Item: TryInto<C::Item>,
+        C: IntoIterator + FromIterator<C::Item>,
+    {
+        let mut errors = Vec::new();
+        let mut convert = |(index, item): (usize, Self::Item)| match item.try_to::<C::Item>() {
+            Ok(value) => Some(value),
+            Err(error) => {
+                errors.push(IndexedError { index, error });
+                None
+            }
+        };
+        let mut iter = self.enumerate();
+        let collection = iter.by_ref().filter_map(&mut convert).collect();
+        // `C` may stop consuming early, but every item still needs to be checked.
+        iter.for_each(|item| {
+            convert(item);
+        });
+        if errors.is_empty() {
+            Ok(collection)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Blanket impl for all iterators.
+/// This makes sure that every iterator implements `IteratorTo` and
+/// that no downstream impls can exist.
+impl<I: Iterator> IteratorTo for I {}
+
+macro_rules! adapter {
+    ($(#[$attr:meta])* $name:ident, $bound:ident, $item:ty, $convert:expr) => {
+        $(#[$attr])*
+        #[must_use = "iterators are lazy and do nothing unless consumed"]
+        pub struct $name<I, T> {
+            iter: I,
+            target: PhantomData<fn() -> T>,
+        }
+
+        impl<I: Clone, T> Clone for $name<I, T> {
+            fn clone(&self) -> Self {
+                $name {
+                    iter: self.iter.clone(),
+                    target: PhantomData,
+                }
+            }
+        }
+
+        impl<I: fmt::Debug, T> fmt::Debug for $name<I, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name)).field("iter", &self.iter).finish()
+            }
+        }
+
+        impl<I: Iterator, T> Iterator for $name<I, T>
+        where
+            I::Item: $bound<T>,
+        {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next().map($convert)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<I: DoubleEndedIterator, T> DoubleEndedIterator for $name<I, T>
+        where
+            I::Item: $bound<T>,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back().map($convert)
+            }
+        }
+
+        impl<I: ExactSizeIterator, T> ExactSizeIterator for $name<I, T>
+        where
+            I::Item: $bound<T>,
+        {
+            #[inline]
+            fn len(&self) -> usize {
+                self.iter.len()
+            }
+        }
+
+        impl<I: FusedIterator, T> FusedIterator for $name<I, T> where I::Item: $bound<T> {}
+    };
+}
+
+adapter!(
+    /// Iterator adapter returned by [`map_to`](IteratorTo::map_to).
+    MapTo,
+    Into,
+    T,
+    Into::into
+);
+
+adapter!(
+    /// Iterator adapter returned by [`try_map_to`](IteratorTo::try_map_to).
+    TryMapTo,
+    TryInto,
+    Result<T, <I::Item as TryInto<T>>::Error>,
+    TryInto::try_into
+);
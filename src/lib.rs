@@ -9,8 +9,8 @@
 //!
 //! Being a micro-crate, it tries to be as nice of a dependency as possible and has:
 //!
-//! - No dependencies of its own
-//! - No feature flags
+//! - No dependencies of its own, unless the `derive` feature is enabled
+//! - No feature flags enabled by default
 //! - No `build.rs`
 //! - `#![no_std]`
 //! - `#![forbid(unsafe_code)]`
@@ -84,14 +84,536 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Converting from references
+//!
+//! The [`ref_to`](To::ref_to) method converts from `&self` without wrapping the
+//! receiver in parentheses, and [`cloned_to`](To::cloned_to) converts a clone:
+//!
+//! ```
+//! use to_method::To as _;
+//!
+//! struct Config {
+//!     name: String,
+//! }
+//!
+//! struct ConfigView<'a> {
+//!     name: &'a str,
+//! }
+//!
+//! impl<'a> From<&'a Config> for ConfigView<'a> {
+//!     fn from(config: &'a Config) -> Self {
+//!         ConfigView { name: &config.name }
+//!     }
+//! }
+//!
+//! let config = Config { name: String::from("prod") };
+//! assert_eq!(config.ref_to::<ConfigView>().name, "prod");
+//!
+//! let name = String::from("prod");
+//! let boxed = name.cloned_to::<Box<str>>();
+//! assert_eq!(name, *boxed);
+//! ```
+//!
+//! # Saturating conversions
+//!
+//! The [`to_saturating`](To::to_saturating) method converts between primitive
+//! numeric types, clamping values that don't fit to the bounds of the target type:
+//!
+//! ```
+//! use to_method::To as _;
+//!
+//! assert_eq!(300_u16.to_saturating::<u8>(), 255);
+//! assert_eq!((-5_i32).to_saturating::<u8>(), 0);
+//! assert_eq!(f64::NAN.to_saturating::<i32>(), 0);
+//! ```
+//!
+//! # Wrapping conversions
+//!
+//! The [`to_wrapping`](To::to_wrapping) method truncates between primitive
+//! integer types just like `as` does, but is named and searchable:
+//!
+//! ```
+//! use to_method::To as _;
+//!
+//! assert_eq!(300_u16.to_wrapping::<u8>(), 44);
+//! assert_eq!((-1_i32).to_wrapping::<u16>(), u16::MAX);
+//! ```
+//!
+//! # Rounding conversions
+//!
+//! Core has no `TryFrom` impls from floats to integers, so `try_to` can't be used for
+//! that. The [`to_rounded`](To::to_rounded) method fills the gap, taking an explicit
+//! [`Rounding`](crate::Rounding) mode:
+//!
+//! ```
+//! use to_method::{Rounding, RoundingError, To as _};
+//!
+//! assert_eq!(2.5_f64.to_rounded::<i32>(Rounding::NearestTiesEven), Ok(2));
+//! assert_eq!(2.5_f64.to_rounded::<i32>(Rounding::NearestTiesAway), Ok(3));
+//! assert_eq!((-2.5_f32).to_rounded::<i8>(Rounding::Floor), Ok(-3));
+//! assert_eq!(255.5_f64.to_rounded::<u8>(Rounding::Ceil), Err(RoundingError::OutOfRange));
+//! assert_eq!(f64::NAN.to_rounded::<u8>(Rounding::TowardZero), Err(RoundingError::NaN));
+//! ```
+//!
+//! # Exact conversions
+//!
+//! `Into` only covers the conversions that can never lose information. The
+//! [`try_to_exact`](To::try_to_exact) method covers all other numeric conversions,
+//! succeeding only if the value round-trips without loss:
+//!
+//! ```
+//! use to_method::{ExactError, To as _};
+//!
+//! assert_eq!((1_u64 << 53).try_to_exact::<f64>(), Ok(9007199254740992.0));
+//! assert_eq!(((1_u64 << 53) + 1).try_to_exact::<f64>(), Err(ExactError::Inexact));
+//! assert_eq!(0.5_f64.try_to_exact::<f32>(), Ok(0.5));
+//! assert_eq!(0.1_f64.try_to_exact::<f32>(), Err(ExactError::Inexact));
+//! assert_eq!((-42.0_f64).try_to_exact::<i64>(), Ok(-42));
+//! assert_eq!(1e19_f64.try_to_exact::<i64>(), Err(ExactError::OutOfRange));
+//! ```
+//!
+//! # Lossy casts
+//!
+//! The [`cast`](To::cast) method is a method-call equivalent of the primitive
+//! `as` cast, with the same semantics:
+//!
+//! ```
+//! use to_method::To as _;
+//!
+//! assert_eq!(300_u16.cast::<u8>(), 44);
+//! assert_eq!(1e10_f64.cast::<i32>(), i32::MAX);
+//! assert_eq!(0.1_f64.cast::<f32>(), 0.1_f32);
+//! assert_eq!('a'.cast::<u8>(), 97);
+//! assert_eq!(97_u8.cast::<char>(), 'a');
+//! assert_eq!(true.cast::<i32>(), 1);
+//! ```
+//!
+//! # Conversion strategies
+//!
+//! Generic code can take the conversion policy as a type parameter by using
+//! [`cast_with`](To::cast_with) with one of the zero-sized types from the
+//! [`strategy`](crate::strategy) module:
+//!
+//! ```
+//! use to_method::strategy::{CastStrategy, Saturating, Wrapping};
+//! use to_method::To as _;
+//!
+//! fn quantize<S: CastStrategy<i32, u8, Output = u8>>(samples: &[i32]) -> [u8; 2] {
+//!     [samples[0].cast_with::<u8, S>(), samples[1].cast_with::<u8, S>()]
+//! }
+//!
+//! assert_eq!(quantize::<Saturating>(&[-1, 256]), [0, 255]);
+//! assert_eq!(quantize::<Wrapping>(&[-1, 256]), [255, 0]);
+//! ```
+//!
+//! # `AsRef` and `AsMut`
+//!
+//! `AsRef::as_ref` has the same problem as `Into::into`: the target type is on the
+//! trait, so calls are ambiguous whenever several `AsRef` impls exist. The
+//! [`AsTo`](crate::AsTo) trait moves it to the method, and works on unsized
+//! receivers too:
+//!
+//! ```
+//! use std::ffi::OsStr;
+//! use std::path::Path;
+//! use to_method::AsTo as _;
+//!
+//! let s = "some/file.txt";
+//!
+//! // `s.as_ref()` alone would be ambiguous here.
+//! let path = s.as_to::<Path>();
+//! let os_str = s.as_to::<OsStr>();
+//! let bytes = s.as_to::<[u8]>();
+//!
+//! assert_eq!(path.extension(), Some(OsStr::new("txt")));
+//! assert_eq!(os_str.len(), bytes.len());
+//!
+//! let mut v = vec![1, 2, 3];
+//! v.as_mut_to::<[i32]>().reverse();
+//! assert_eq!(v, [3, 2, 1]);
+//! ```
+//!
+//! The [`BorrowTo`](crate::BorrowTo) trait does the same for `Borrow` and `BorrowMut`:
+//!
+//! ```
+//! use std::collections::HashSet;
+//! use to_method::BorrowTo as _;
+//!
+//! let key = String::from("apple");
+//! let set: HashSet<&str> = ["apple", "pear"].iter().copied().collect();
+//!
+//! assert!(set.contains(key.borrow_to::<str>()));
+//! ```
+//!
+//! # Parsing
+//!
+//! `str::parse` only works on `&str`. The [`parse_to`](crate::ParseTo::parse_to)
+//! method also works on byte slices, reporting invalid UTF-8 as its own error variant.
+//! It is implemented for `str`, `[u8]` and, on Unix, `OsStr`, and reaches owning types
+//! through deref:
+//!
+//! ```
+//! use std::borrow::Cow;
+//! use to_method::{ParseTo as _, ParseToError};
+//!
+//! assert_eq!(String::from("42").parse_to::<u8>(), Ok(42));
+//! assert_eq!(Cow::Borrowed("-1").parse_to::<i32>(), Ok(-1));
+//! assert_eq!(b"true".parse_to::<bool>(), Ok(true));
+//! assert!(matches!(b"\xff".parse_to::<u8>(), Err(ParseToError::Utf8(_))));
+//! assert!(matches!(b"300".parse_to::<u8>(), Err(ParseToError::Parse(_))));
+//!
+//! # #[cfg(all(feature = "std", unix))]
+//! assert_eq!(std::ffi::OsStr::new("7").parse_to::<u8>(), Ok(7));
+//! ```
+//!
+//! # Iterators
+//!
+//! The [`IteratorTo`](crate::IteratorTo) trait provides lazy adapters that
+//! convert every item, replacing `.map(Into::<T>::into)`:
+//!
+//! ```
+//! use to_method::IteratorTo as _;
+//!
+//! let wide: Vec<u32> = [1_u8, 2, 3].iter().copied().map_to::<u32>().collect();
+//! assert_eq!(wide, [1, 2, 3]);
+//!
+//! let mut narrow = [1_u32, 256].iter().copied().try_map_to::<u8>();
+//! assert_eq!(narrow.next(), Some(Ok(1)));
+//! assert!(narrow.next().unwrap().is_err());
+//! ```
+//!
+//! Fallible conversions can also be collected directly, reporting the index of
+//! the first element that failed:
+//!
+//! ```
+//! use to_method::IteratorTo as _;
+//!
+//! let wire: Vec<i64> = vec![1, 2, -3, 4];
+//!
+//! let err = wire.iter().copied().try_collect_to::<Vec<u32>>().unwrap_err();
+//! assert_eq!(err.index, 2);
+//! ```
+//!
+//! With the `alloc` feature, [`try_to_all`](crate::IteratorTo::try_to_all) and its
+//! slice counterpart [`SliceTo::try_to_all`](crate::SliceTo::try_to_all) report
+//! every failure instead:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use to_method::{IteratorTo as _, SliceTo as _};
+//!
+//! let rows: &[i64] = &[1, -2, 3, 1 << 40];
+//!
+//! let errors = rows.try_to_all::<Vec<u32>>().unwrap_err();
+//! assert_eq!(errors.iter().map(|e| e.index).collect::<Vec<_>>(), [1, 3]);
+//!
+//! assert_eq!(rows[..1].try_to_all::<Vec<u32>>(), Ok(vec![1]));
+//! assert_eq!(rows.iter().copied().try_to_all::<Vec<u32>>().unwrap_err().len(), 2);
+//! # }
+//! ```
+//!
+//! # `Option` and `Result`
+//!
+//! The [`OptionTo`](crate::OptionTo) and [`ResultTo`](crate::ResultTo) traits
+//! provide combinators that put the target type on the method:
+//!
+//! ```
+//! use core::num::TryFromIntError;
+//! use to_method::{OptionTo as _, ResultTo as _};
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct MyError;
+//!
+//! impl From<TryFromIntError> for MyError {
+//!     fn from(_: TryFromIntError) -> Self {
+//!         MyError
+//!     }
+//! }
+//!
+//! assert_eq!(Some(5_u8).map_to::<u32>(), Some(5));
+//! assert!(Some(300_u32).try_map_to::<u8>().is_err());
+//! assert_eq!(None::<u32>.try_map_to::<u8>(), Ok(None));
+//!
+//! let res: Result<u8, TryFromIntError> = Ok(5);
+//! assert_eq!(res.map_to::<u64>(), Ok(5));
+//! assert_eq!(res.map_err_to::<MyError>(), Ok(5));
+//! assert_eq!(res.ok_to::<i16>(), Some(5));
+//!
+//! let res: Result<i64, MyError> = Ok(-1);
+//! assert_eq!(res.and_try_to::<u32>(), Err(MyError));
+//! ```
+//!
+//! # Explained errors
+//!
+//! Many `TryInto` errors, like `TryFromIntError`, say little about what went wrong.
+//! The [`try_to_explained`](To::try_to_explained) method wraps them in a
+//! [`ConversionError`](crate::ConversionError) that keeps the rejected value and
+//! names the types involved:
+//!
+//! ```
+//! use to_method::To as _;
+//!
+//! let err = 300_i32.try_to_explained::<u8>().unwrap_err();
+//!
+//! assert_eq!(*err.value(), 300);
+//! assert_eq!(
+//!     err.to_string(),
+//!     "cannot convert 300 from `i32` to `u8`: out of range integral type conversion attempted",
+//! );
+//! ```
+//!
+//! Keeping the value requires `Clone`, and `try_to_explained` clones it before every
+//! attempt, even ones that succeed. The [`try_to_named`](To::try_to_named) method
+//! works on any type and never clones, but its [`NamedError`](crate::NamedError)
+//! only names the types:
+//!
+//! ```
+//! use to_method::To as _;
+//!
+//! let err = 300_i32.try_to_named::<u8>().unwrap_err();
+//!
+//! assert_eq!(
+//!     err.to_string(),
+//!     "cannot convert from `i32` to `u8`: out of range integral type conversion attempted",
+//! );
+//! ```
+//!
+//! # Keeping the value on failure
+//!
+//! A failed `try_to` consumes its input unless the error type hands it back. The
+//! [`try_to_or_return`](To::try_to_or_return) method always returns the original
+//! value on failure, based on the [`TryFromOrReturn`](crate::TryFromOrReturn) trait:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use to_method::To as _;
+//!
+//! let v = vec![1, 2, 3];
+//!
+//! let (v, err) = v.try_to_or_return::<[i32; 2]>().unwrap_err();
+//! assert_eq!(err.found, 3);
+//!
+//! let array = v.try_to_or_return::<[i32; 3]>().unwrap();
+//! assert_eq!(array, [1, 2, 3]);
+//! # }
+//! ```
+//!
+//! # Converting through an intermediate type
+//!
+//! Conversions are not transitive, so `u8` to `f64` via `u32` needs two calls. The
+//! [`to_via`](To::to_via) and [`try_to_via`](To::try_to_via) methods chain them:
+//!
+//! ```
+//! use to_method::{To as _, ViaError};
+//!
+//! struct Meters(u32);
+//!
+//! impl From<Meters> for u32 {
+//!     fn from(m: Meters) -> u32 {
+//!         m.0
+//!     }
+//! }
+//!
+//! assert_eq!(Meters(7).to_via::<u32, f64>(), 7.0);
+//!
+//! assert!(matches!((-1_i64).try_to_via::<u16, u8>(), Err(ViaError::First(_))));
+//! assert!(matches!(300_i64.try_to_via::<u16, u8>(), Err(ViaError::Second(_))));
+//! ```
+//!
+//! # Panicking conversions
+//!
+//! `x.try_to::<u32>().unwrap()` panics with a generic message. The
+//! [`to_expect`](To::to_expect) and [`to_unwrap`](To::to_unwrap) methods report the
+//! types involved, at the location of their caller:
+//!
+//! ```should_panic
+//! use to_method::To as _;
+//!
+//! // Panics with "length must fit into a byte: cannot convert from `i32` to `u8`: TryFromIntError(())"
+//! let len = 300_i32.to_expect::<u8>("length must fit into a byte");
+//! ```
+//!
+//! For types that implement `Clone` and `Debug`, [`to_expect_debug`](To::to_expect_debug)
+//! and [`to_unwrap_debug`](To::to_unwrap_debug) also report the value:
+//!
+//! ```should_panic
+//! use to_method::To as _;
+//!
+//! // Panics with "called `To::to_unwrap_debug` on a failed conversion: cannot convert [1, 2, 3] from `alloc::vec::Vec<u8>` to `[u8; 2]`: [1, 2, 3]"
+//! let pair = vec![1_u8, 2, 3].to_unwrap_debug::<[u8; 2]>();
+//! ```
+//!
+//! # Falling back on failure
+//!
+//! The [`try_to_or`](To::try_to_or), [`try_to_or_else`](To::try_to_or_else) and
+//! [`try_to_or_default`](To::try_to_or_default) methods replace failed conversions
+//! with a fallback value:
+//!
+//! ```
+//! use to_method::To as _;
+//!
+//! assert_eq!(300_i32.try_to_or::<u8>(u8::MAX), u8::MAX);
+//! assert_eq!((-1_i32).try_to_or_else::<u8>(|_err| 0), 0);
+//! assert_eq!(42_i32.try_to_or_default::<u8>(), 42);
+//! assert_eq!((-1_i32).try_to_or_default::<u8>(), 0);
+//! ```
+//!
+//! # Conversions between foreign types
+//!
+//! The orphan rules forbid implementing `From` when both types are foreign. The
+//! [`impl_convert_from!`](crate::impl_convert_from) macro declares such conversions
+//! through a local marker type instead, and the [`convert_to`](crate::ConvertTo::convert_to)
+//! method picks up both kinds:
+//!
+//! ```
+//! use std::net::Ipv4Addr;
+//! use std::time::Duration;
+//! use to_method::ConvertTo as _;
+//!
+//! enum Conversions {}
+//!
+//! to_method::impl_convert_from! {
+//!     via Conversions;
+//!
+//!     fn(addr: Ipv4Addr) -> Duration {
+//!         Duration::from_secs(u32::from(addr).into())
+//!     }
+//! }
+//!
+//! let addr = Ipv4Addr::new(0, 0, 1, 0);
+//! assert_eq!(addr.convert_to::<Duration>(), Duration::from_secs(256));
+//! assert_eq!(addr.convert_to::<u32>(), 256);
+//! ```
+//!
+//! # Tuples
+//!
+//! Core has no element-wise `From` impls for tuples. The [`TupleTo`](crate::TupleTo)
+//! trait converts tuples of up to 12 elements one element at a time, and the
+//! fallible form reports which position failed:
+//!
+//! ```
+//! use to_method::{TupleError, TupleTo as _};
+//!
+//! let point = (3_u8, -4_i16);
+//! assert_eq!(point.tuple_to::<(u32, i64)>(), (3, -4));
+//!
+//! let key = (1_i64, -2_i64, 3_i64);
+//! let err = key.try_tuple_to::<(u32, u32, u32)>().unwrap_err();
+//! assert_eq!(err.position(), 1);
+//! assert!(matches!(err, TupleError::At1(_)));
+//! ```
+//!
+//! # Conversions with context
+//!
+//! Some conversions need external state, like an interner or a unit scale. The
+//! [`to_with`](crate::ToWith::to_with) and [`try_to_with`](crate::ToWith::try_to_with)
+//! methods pass it along to [`FromWith`](crate::FromWith) and [`TryFromWith`](crate::TryFromWith)
+//! impls. Plain `From` and `TryFrom` impls work with the empty context `()`. The context
+//! type is inferred from the argument, so only the target type has to be named:
+//!
+//! ```
+//! use to_method::{FromWith, ToWith as _, TryFromWith};
+//!
+//! struct Interner(Vec<&'static str>);
+//!
+//! #[derive(Clone, Copy)]
+//! struct Symbol(usize);
+//!
+//! impl FromWith<Symbol, Interner> for &'static str {
+//!     fn from_with(symbol: Symbol, interner: &Interner) -> Self {
+//!         interner.0[symbol.0]
+//!     }
+//! }
+//!
+//! impl TryFromWith<&str, Interner> for Symbol {
+//!     type Error = ();
+//!
+//!     fn try_from_with(s: &str, interner: &Interner) -> Result<Self, ()> {
+//!         interner.0.iter().position(|&t| t == s).map(Symbol).ok_or(())
+//!     }
+//! }
+//!
+//! let interner = Interner(vec!["foo", "bar"]);
+//!
+//! let bar = "bar".try_to_with::<Symbol>(&interner).unwrap();
+//! assert_eq!(bar.to_with::<&str>(&interner), "bar");
+//! assert!("baz".try_to_with::<Symbol>(&interner).is_err());
+//!
+//! assert_eq!(5_u8.to_with::<u32>(&()), 5);
+//! ```
+//!
+//! # Feature flags
+//!
+//! - `alloc`: Enables the [`try_to_all`](crate::IteratorTo::try_to_all) methods,
+//!   which return all errors in a `Vec`, and the [`TryFromOrReturn`](crate::TryFromOrReturn)
+//!   impls for `alloc` types.
+//! - `std`: Implies `alloc`. Implements [`ParseTo`](crate::ParseTo) for `OsStr` on Unix
+//!   and `std::error::Error` for the error types of this crate.
+//! - `derive`: Re-exports the derive macros from
+//!   [`to_method_derive`](https://docs.rs/to_method_derive), such as `FromInner`
+//!   and `IntoInner` for newtypes, `TryFromRepr` and `IntoRepr` for fieldless enums,
+//!   and `ConvertFrom` for field-wise struct conversions.
 
 #![no_std]
 #![forbid(missing_docs)]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::convert::TryInto;
+use core::fmt::Debug;
+use strategy::CastStrategy;
+
+mod as_to;
+mod borrow_to;
+mod combinators;
+mod convert;
+mod error;
+mod exact;
+mod float;
+mod iter;
+mod lossy;
+mod or_return;
+mod parse;
+mod rounding;
+mod saturating;
+#[cfg(feature = "alloc")]
+mod slice;
+mod tuple;
+mod with;
+mod wrapping;
+
+pub mod strategy;
 
-/// Extension trait providing the [`to`](To::to) and [`try_to`](To::try_to) methods.
+pub use as_to::AsTo;
+pub use borrow_to::BorrowTo;
+pub use combinators::{OptionTo, ResultTo};
+pub use convert::{ConvertFrom, ConvertTo, ViaFrom};
+pub use error::{ConversionError, FieldError, InvalidDiscriminant, NamedError, ViaError};
+pub use exact::{ExactError, TryFromExact};
+pub use iter::{IndexedError, IteratorTo, MapTo, TryMapTo};
+pub use lossy::LossyFrom;
+pub use or_return::{LengthMismatch, StillShared, TryFromOrReturn};
+pub use parse::{ParseTo, ParseToError};
+pub use rounding::{RoundFrom, Rounding, RoundingError};
+pub use saturating::SaturatingFrom;
+#[cfg(feature = "alloc")]
+pub use slice::SliceTo;
+pub use tuple::{TryTupleFrom, TupleError, TupleFrom, TupleTo};
+pub use with::{FromWith, ToWith, TryFromWith};
+pub use wrapping::WrappingFrom;
+
+#[cfg(feature = "derive")]
+pub use to_method_derive::{ConvertFrom, FromInner, IntoInner, IntoRepr, TryFromRepr};
+
+/// Extension trait providing the [`to`](To::to) and [`try_to`](To::try_to) methods,
+/// along with their numeric variants such as [`to_saturating`](To::to_saturating).
 pub trait To {
     /// Converts to `T` by calling `Into<T>::into`.
     #[inline(always)]
@@ -109,6 +631,252 @@ pub trait To {
     {
         <Self as TryInto<T>>::try_into(self)
     }
+
+    /// Converts a reference to `T` by calling `Into<T>::into` on `&Self`.
+    #[inline(always)]
+    fn ref_to<'a, T>(&'a self) -> T
+    where
+        &'a Self: Into<T>,
+    {
+        <&'a Self as Into<T>>::into(self)
+    }
+
+    /// Converts a clone to `T` by calling `Into<T>::into`.
+    #[inline(always)]
+    fn cloned_to<T>(&self) -> T
+    where
+        Self: Clone + Into<T>,
+    {
+        <Self as Into<T>>::into(self.clone())
+    }
+
+    /// Tries to convert to `T` by calling `TryInto<T>::try_into`, returning `default` on failure.
+    #[inline(always)]
+    fn try_to_or<T>(self, default: T) -> T
+    where
+        Self: TryInto<T>,
+    {
+        self.try_to::<T>().unwrap_or(default)
+    }
+
+    /// Tries to convert to `T` by calling `TryInto<T>::try_into`, computing a fallback
+    /// from the conversion error on failure.
+    #[inline(always)]
+    fn try_to_or_else<T>(self, f: impl FnOnce(<Self as TryInto<T>>::Error) -> T) -> T
+    where
+        Self: TryInto<T>,
+    {
+        self.try_to::<T>().unwrap_or_else(f)
+    }
+
+    /// Tries to convert to `T` by calling `TryInto<T>::try_into`, returning `T::default()`
+    /// on failure.
+    #[inline(always)]
+    fn try_to_or_default<T>(self) -> T
+    where
+        Self: TryInto<T>,
+        T: Default,
+    {
+        self.try_to::<T>().unwrap_or_default()
+    }
+
+    /// Tries to convert to `T` by calling `TryInto<T>::try_into`, explaining failures.
+    ///
+    /// On failure, the returned [`ConversionError`] holds the original value, which is
+    /// why `Self` has to be cloned before every conversion attempt. Use
+    /// [`try_to_named`](To::try_to_named) if that is too expensive or `Self` isn't `Clone`.
+    fn try_to_explained<T>(self) -> Result<T, ConversionError<Self, <Self as TryInto<T>>::Error>>
+    where
+        Self: Clone + TryInto<T>,
+    {
+        match self.clone().try_into() {
+            Ok(value) => Ok(value),
+            Err(error) => Err(ConversionError::new::<T>(self, error)),
+        }
+    }
+
+    /// Tries to convert to `T` by calling `TryInto<T>::try_into`, naming the source
+    /// and target types on failure.
+    ///
+    /// Unlike [`try_to_explained`](To::try_to_explained), this never clones `Self`.
+    #[inline]
+    fn try_to_named<T>(self) -> Result<T, NamedError<<Self as TryInto<T>>::Error>>
+    where
+        Self: TryInto<T>,
+    {
+        self.try_into().map_err(NamedError::new::<Self, T>)
+    }
+
+    /// Converts to `T` by calling `TryInto<T>::try_into`, panicking with `msg` on failure.
+    ///
+    /// The panic message also contains the names of the types involved.
+    #[track_caller]
+    fn to_expect<T>(self, msg: &str) -> T
+    where
+        Self: TryInto<T>,
+        <Self as TryInto<T>>::Error: Debug,
+    {
+        match self.try_to_named::<T>() {
+            Ok(value) => value,
+            Err(err) => err.panic(msg),
+        }
+    }
+
+    /// Converts to `T` by calling `TryInto<T>::try_into`, panicking on failure.
+    ///
+    /// The panic message contains the names of the types involved.
+    #[track_caller]
+    fn to_unwrap<T>(self) -> T
+    where
+        Self: TryInto<T>,
+        <Self as TryInto<T>>::Error: Debug,
+    {
+        self.to_expect("called `To::to_unwrap` on a failed conversion")
+    }
+
+    /// Converts to `T` by calling `TryInto<T>::try_into`, panicking with `msg` on failure.
+    ///
+    /// The panic message also contains the value and the names of the types involved.
+    /// Like [`try_to_explained`](To::try_to_explained), this clones `Self` before the
+    /// conversion attempt.
+    #[track_caller]
+    fn to_expect_debug<T>(self, msg: &str) -> T
+    where
+        Self: Clone + Debug + TryInto<T>,
+        <Self as TryInto<T>>::Error: Debug,
+    {
+        match self.try_to_explained::<T>() {
+            Ok(value) => value,
+            Err(err) => err.panic(msg),
+        }
+    }
+
+    /// Converts to `T` by calling `TryInto<T>::try_into`, panicking on failure.
+    ///
+    /// The panic message contains the value and the names of the types involved.
+    #[track_caller]
+    fn to_unwrap_debug<T>(self) -> T
+    where
+        Self: Clone + Debug + TryInto<T>,
+        <Self as TryInto<T>>::Error: Debug,
+    {
+        self.to_expect_debug("called `To::to_unwrap_debug` on a failed conversion")
+    }
+
+    /// Converts to `T` via `M` by calling `Into<M>::into` and then `Into<T>::into`.
+    #[inline(always)]
+    fn to_via<M, T>(self) -> T
+    where
+        Self: Into<M>,
+        M: Into<T>,
+    {
+        self.to::<M>().to::<T>()
+    }
+
+    /// Tries to convert to `T` via `M` by calling `TryInto<M>::try_into` and then
+    /// `TryInto<T>::try_into`.
+    ///
+    /// The returned [`ViaError`] says which of the two conversions failed.
+    #[allow(clippy::type_complexity)]
+    fn try_to_via<M, T>(
+        self,
+    ) -> Result<T, ViaError<<Self as TryInto<M>>::Error, <M as TryInto<T>>::Error>>
+    where
+        Self: TryInto<M>,
+        M: TryInto<T>,
+    {
+        self.try_to::<M>()
+            .map_err(ViaError::First)?
+            .try_to::<T>()
+            .map_err(ViaError::Second)
+    }
+
+    /// Converts to `T` by calling `SaturatingFrom<Self>::saturating_from`.
+    #[inline(always)]
+    fn to_saturating<T>(self) -> T
+    where
+        Self: Sized,
+        T: SaturatingFrom<Self>,
+    {
+        T::saturating_from(self)
+    }
+
+    /// Converts to `T` by calling `WrappingFrom<Self>::wrapping_from`.
+    #[inline(always)]
+    fn to_wrapping<T>(self) -> T
+    where
+        Self: Sized,
+        T: WrappingFrom<Self>,
+    {
+        T::wrapping_from(self)
+    }
+
+    /// Rounds and converts to `T` by calling `RoundFrom<Self>::round_from`.
+    #[inline(always)]
+    fn to_rounded<T>(self, mode: Rounding) -> Result<T, RoundingError>
+    where
+        Self: Sized,
+        T: RoundFrom<Self>,
+    {
+        T::round_from(self, mode)
+    }
+
+    /// Tries to convert to `T` without loss by calling `TryFromExact<Self>::try_from_exact`.
+    #[inline(always)]
+    fn try_to_exact<T>(self) -> Result<T, ExactError>
+    where
+        Self: Sized,
+        T: TryFromExact<Self>,
+    {
+        T::try_from_exact(self)
+    }
+
+    /// Converts to `T` as if by an `as` cast, by calling `LossyFrom<Self>::lossy_from`.
+    #[inline(always)]
+    fn cast<T>(self) -> T
+    where
+        Self: Sized,
+        T: LossyFrom<Self>,
+    {
+        T::lossy_from(self)
+    }
+
+    /// Converts to `T` using the conversion strategy `S`.
+    ///
+    /// See the [`strategy`](crate::strategy) module for the available strategies.
+    #[inline(always)]
+    fn cast_with<T, S>(self) -> <S as CastStrategy<Self, T>>::Output
+    where
+        Self: Sized,
+        S: CastStrategy<Self, T>,
+    {
+        S::cast(self)
+    }
+
+    /// Tries to convert to `T` by calling `TryFromOrReturn<Self>::try_from_or_return`.
+    ///
+    /// On failure, the original value is returned along with the error.
+    #[inline(always)]
+    fn try_to_or_return<T>(self) -> Result<T, (Self, <T as TryFromOrReturn<Self>>::Error)>
+    where
+        Self: Sized,
+        T: TryFromOrReturn<Self>,
+    {
+        T::try_from_or_return(self)
+    }
+
+    /// Converts to `T` by calling `ConvertFrom<Self, M>::convert_from` with an explicit marker `M`.
+    ///
+    /// Use this instead of [`convert_to`](ConvertTo::convert_to) when more than one
+    /// marker provides the conversion.
+    #[inline(always)]
+    fn convert_to_via<T, M>(self) -> T
+    where
+        Self: Sized,
+        T: ConvertFrom<Self, M>,
+    {
+        T::convert_from(self)
+    }
 }
 
 /// Blanket impl for all types.
@@ -0,0 +1,40 @@
+/// Conversion with the exact semantics of an `as` cast.
+///
+/// Implemented for every pair of primitive numeric types, for `char` to every
+/// primitive integer type, for `u8` to `char` and for `bool` to every primitive
+/// integer type. Float-to-int casts saturate and map NaN to `0`.
+///
+/// There is no impl from `u32` to `char` because no such `as` cast exists;
+/// use [`try_to`](crate::To::try_to) for that instead.
+pub trait LossyFrom<T>: Sized {
+    /// Converts `value` as if by `value as Self`.
+    fn lossy_from(value: T) -> Self;
+}
+
+macro_rules! impl_lossy {
+    ($($src:ty),* => $dsts:tt) => {
+        $(
+            impl_lossy!(@ $src => $dsts);
+        )*
+    };
+    (@ $src:ty => ($($dst:ty),*)) => {
+        $(
+            impl LossyFrom<$src> for $dst {
+                #[inline]
+                fn lossy_from(value: $src) -> Self {
+                    value as $dst
+                }
+            }
+        )*
+    };
+}
+
+impl_lossy!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+    => (u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64)
+);
+impl_lossy!(
+    char, bool
+    => (u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize)
+);
+impl_lossy!(u8 => (char));
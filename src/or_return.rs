@@ -0,0 +1,122 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc, string::String, vec::Vec};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+#[cfg(feature = "alloc")]
+use core::str::Utf8Error;
+
+/// Fallible conversion that hands the original value back on failure.
+///
+/// With the `alloc` feature, this is implemented for the standard conversions that
+/// already return their input on failure:
+///
+/// - `Vec<T>` to `[T; N]` and `Box<[T; N]>`
+/// - `Box<[T]>`, `Rc<[T]>` and `Arc<[T]>` to their array counterparts
+/// - `Rc<T>` and `Arc<T>` to `T`, in the style of `Rc::try_unwrap`
+/// - `Vec<u8>` to `String`
+pub trait TryFromOrReturn<T>: Sized {
+    /// The type returned alongside the original value when the conversion fails.
+    type Error;
+
+    /// Tries to convert `value`, returning it along with the error on failure.
+    fn try_from_or_return(value: T) -> Result<Self, (T, Self::Error)>;
+}
+
+/// The error type returned when a sequence doesn't have the length of the target array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    /// The length of the target array.
+    pub expected: usize,
+    /// The length of the source sequence.
+    pub found: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} elements, found {}", self.expected, self.found)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthMismatch {}
+
+/// The error type returned when a shared pointer can't be unwrapped because
+/// other references to its value exist.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StillShared;
+
+impl fmt::Display for StillShared {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value is still shared")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StillShared {}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_array {
+    ($($(#[$attr:meta])* $src:ty => $dst:ty;)*) => {
+        $(
+            $(#[$attr])*
+            impl<T, const N: usize> TryFromOrReturn<$src> for $dst {
+                type Error = LengthMismatch;
+
+                #[inline]
+                fn try_from_or_return(value: $src) -> Result<Self, ($src, LengthMismatch)> {
+                    <$dst>::try_from(value).map_err(|value| {
+                        let found = value.len();
+                        (value, LengthMismatch { expected: N, found })
+                    })
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_array! {
+    Vec<T> => [T; N];
+    Vec<T> => Box<[T; N]>;
+    Box<[T]> => Box<[T; N]>;
+    Rc<[T]> => Rc<[T; N]>;
+    #[cfg(target_has_atomic = "ptr")]
+    Arc<[T]> => Arc<[T; N]>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryFromOrReturn<Rc<T>> for T {
+    type Error = StillShared;
+
+    #[inline]
+    fn try_from_or_return(value: Rc<T>) -> Result<Self, (Rc<T>, StillShared)> {
+        Rc::try_unwrap(value).map_err(|value| (value, StillShared))
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> TryFromOrReturn<Arc<T>> for T {
+    type Error = StillShared;
+
+    #[inline]
+    fn try_from_or_return(value: Arc<T>) -> Result<Self, (Arc<T>, StillShared)> {
+        Arc::try_unwrap(value).map_err(|value| (value, StillShared))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFromOrReturn<Vec<u8>> for String {
+    type Error = Utf8Error;
+
+    #[inline]
+    fn try_from_or_return(value: Vec<u8>) -> Result<Self, (Vec<u8>, Utf8Error)> {
+        String::from_utf8(value).map_err(|err| {
+            let error = err.utf8_error();
+            (err.into_bytes(), error)
+        })
+    }
+}
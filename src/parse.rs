@@ -0,0 +1,68 @@
+use core::fmt;
+use core::str::{self, FromStr, Utf8Error};
+
+#[cfg(all(feature = "std", unix))]
+use std::ffi::OsStr;
+#[cfg(all(feature = "std", unix))]
+use std::os::unix::ffi::OsStrExt;
+
+/// The error type returned by [`parse_to`](ParseTo::parse_to).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseToError<E> {
+    /// The input wasn't valid UTF-8.
+    Utf8(Utf8Error),
+    /// The input was valid UTF-8, but `FromStr` rejected it.
+    Parse(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ParseToError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseToError::Utf8(err) => err.fmt(f),
+            ParseToError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for ParseToError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseToError::Utf8(err) => Some(err),
+            ParseToError::Parse(err) => Some(err),
+        }
+    }
+}
+
+/// Extension trait providing the [`parse_to`](ParseTo::parse_to) method.
+///
+/// Implemented for `str` and `[u8]`, and for `OsStr` on Unix with the `std` feature.
+/// Owning and smart-pointer types like `String`, `Box<str>`, `Cow<str>` and
+/// `Vec<u8>` get it through auto-deref. Other types that only implement
+/// `AsRef<str>` have to call `as_ref()` first.
+pub trait ParseTo {
+    /// Parses into `T` by calling `FromStr::from_str`, after checking for valid UTF-8.
+    fn parse_to<T: FromStr>(&self) -> Result<T, ParseToError<T::Err>>;
+}
+
+impl ParseTo for str {
+    #[inline]
+    fn parse_to<T: FromStr>(&self) -> Result<T, ParseToError<T::Err>> {
+        self.parse().map_err(ParseToError::Parse)
+    }
+}
+
+impl ParseTo for [u8] {
+    #[inline]
+    fn parse_to<T: FromStr>(&self) -> Result<T, ParseToError<T::Err>> {
+        str::from_utf8(self).map_err(ParseToError::Utf8)?.parse_to()
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl ParseTo for OsStr {
+    #[inline]
+    fn parse_to<T: FromStr>(&self) -> Result<T, ParseToError<T::Err>> {
+        self.as_bytes().parse_to()
+    }
+}
@@ -0,0 +1,131 @@
+use core::fmt;
+
+use crate::float::{FitsIn, Float};
+
+/// Rounding mode used by [`to_rounded`](crate::To::to_rounded).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds to the nearest integer, resolving ties toward the even one.
+    NearestTiesEven,
+    /// Rounds to the nearest integer, resolving ties away from zero.
+    NearestTiesAway,
+    /// Rounds toward zero, discarding the fractional part.
+    TowardZero,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds toward positive infinity.
+    Ceil,
+}
+
+/// The error type returned when a float can't be rounded to an integer type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingError {
+    /// The value was NaN.
+    NaN,
+    /// The value was positive or negative infinity.
+    Infinite,
+    /// The rounded value doesn't fit into the target type.
+    OutOfRange,
+}
+
+impl fmt::Display for RoundingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RoundingError::NaN => "cannot convert NaN to an integer",
+            RoundingError::Infinite => "cannot convert an infinite value to an integer",
+            RoundingError::OutOfRange => "rounded value out of range of the integer type",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RoundingError {}
+
+/// Conversion from a float to an integer with an explicit [`Rounding`] mode.
+///
+/// Implemented for `f32`/`f64` to every primitive integer type.
+///
+/// Ties are resolved the same way for negative values as for positive ones:
+///
+/// ```
+/// use to_method::{RoundFrom, Rounding::*};
+///
+/// assert_eq!(i32::round_from(-0.5_f64, NearestTiesEven), Ok(0));
+/// assert_eq!(i32::round_from(-0.5_f64, NearestTiesAway), Ok(-1));
+/// assert_eq!(i32::round_from(-1.5_f64, NearestTiesEven), Ok(-2));
+/// assert_eq!(i32::round_from(-2.5_f64, NearestTiesEven), Ok(-2));
+/// assert_eq!(i32::round_from(-2.5_f64, NearestTiesAway), Ok(-3));
+/// assert_eq!(i32::round_from(-2.5_f64, Floor), Ok(-3));
+/// assert_eq!(i32::round_from(-2.5_f64, Ceil), Ok(-2));
+/// assert_eq!(i32::round_from(-2.5_f64, TowardZero), Ok(-2));
+/// assert_eq!(u8::round_from(-0.5_f64, Ceil), Ok(0));
+/// ```
+///
+/// Values around 2^23 for `f32` and 2^52 for `f64`, beyond which every float is
+/// integral, round correctly:
+///
+/// ```
+/// use to_method::{RoundFrom, Rounding::*};
+///
+/// assert_eq!(i32::round_from(8_388_607.5_f32, TowardZero), Ok(8_388_607));
+/// assert_eq!(i32::round_from(8_388_607.5_f32, NearestTiesEven), Ok(8_388_608));
+/// assert_eq!(i32::round_from(8_388_608.0_f32, Ceil), Ok(8_388_608));
+/// assert_eq!(i32::round_from(16_777_215.0_f32, NearestTiesEven), Ok(16_777_215));
+///
+/// assert_eq!(i64::round_from(4_503_599_627_370_495.5_f64, Floor), Ok(4_503_599_627_370_495));
+/// assert_eq!(i64::round_from(4_503_599_627_370_495.5_f64, Ceil), Ok(4_503_599_627_370_496));
+/// assert_eq!(i64::round_from(4_503_599_627_370_496.0_f64, TowardZero), Ok(4_503_599_627_370_496));
+/// assert_eq!(i64::round_from(4_503_599_627_370_497.0_f64, Floor), Ok(4_503_599_627_370_497));
+/// assert_eq!(i64::round_from(-4_503_599_627_370_495.5_f64, Floor), Ok(-4_503_599_627_370_496));
+/// ```
+///
+/// The range check applies to the rounded value:
+///
+/// ```
+/// use to_method::{RoundFrom, RoundingError, Rounding::*};
+///
+/// assert_eq!(u8::round_from(254.5_f64, NearestTiesEven), Ok(254));
+/// assert_eq!(u8::round_from(255.5_f64, NearestTiesEven), Err(RoundingError::OutOfRange));
+/// assert_eq!(u8::round_from(255.5_f64, TowardZero), Ok(255));
+/// assert_eq!(i8::round_from(-128.5_f64, Ceil), Ok(-128));
+/// assert_eq!(i8::round_from(-128.5_f64, Floor), Err(RoundingError::OutOfRange));
+/// assert_eq!(i32::round_from(2_147_483_520.0_f32, TowardZero), Ok(2_147_483_520));
+/// assert_eq!(i32::round_from(2_147_483_648.0_f32, TowardZero), Err(RoundingError::OutOfRange));
+/// assert_eq!(u64::round_from(18_446_744_073_709_549_568.0_f64, Floor), Ok(18_446_744_073_709_549_568));
+/// assert_eq!(u64::round_from(18_446_744_073_709_551_616.0_f64, Floor), Err(RoundingError::OutOfRange));
+/// assert_eq!(u8::round_from(f32::INFINITY, Floor), Err(RoundingError::Infinite));
+/// ```
+pub trait RoundFrom<T>: Sized {
+    /// Rounds `value` using `mode` and converts the result.
+    fn round_from(value: T, mode: Rounding) -> Result<Self, RoundingError>;
+}
+
+macro_rules! impl_round {
+    ($($src:ty),*) => {
+        $(
+            impl_round!(@ $src => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        )*
+    };
+    (@ $src:ty => $($dst:ty),*) => {
+        $(
+            impl RoundFrom<$src> for $dst {
+                fn round_from(value: $src, mode: Rounding) -> Result<Self, RoundingError> {
+                    if value.is_nan() {
+                        return Err(RoundingError::NaN);
+                    }
+                    if value.is_infinite() {
+                        return Err(RoundingError::Infinite);
+                    }
+                    let rounded = value.round_with(mode);
+                    if FitsIn::<$dst>::fits_in(rounded) {
+                        Ok(rounded as $dst)
+                    } else {
+                        Err(RoundingError::OutOfRange)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_round!(f32, f64);
@@ -0,0 +1,55 @@
+use core::convert::TryFrom;
+
+/// Conversion that clamps out-of-range values to the bounds of the target type.
+///
+/// Implemented for every pair of primitive integer types and for
+/// `f32`/`f64` to every primitive integer type. NaN converts to `0`.
+pub trait SaturatingFrom<T>: Sized {
+    /// Converts `value`, clamping it to `Self::MIN..=Self::MAX`.
+    fn saturating_from(value: T) -> Self;
+}
+
+macro_rules! impl_saturating_int {
+    ($($src:ty),*) => {
+        $(
+            impl_saturating_int!(@ $src => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        )*
+    };
+    (@ $src:ty => $($dst:ty),*) => {
+        $(
+            impl SaturatingFrom<$src> for $dst {
+                #[inline]
+                fn saturating_from(value: $src) -> Self {
+                    match <$dst>::try_from(value) {
+                        Ok(value) => value,
+                        Err(_) if value > 0 => <$dst>::MAX,
+                        Err(_) => <$dst>::MIN,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_saturating_float {
+    ($($src:ty),*) => {
+        $(
+            impl_saturating_float!(@ $src => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        )*
+    };
+    (@ $src:ty => $($dst:ty),*) => {
+        $(
+            impl SaturatingFrom<$src> for $dst {
+                // Float-to-int `as` casts saturate and map NaN to `0`.
+                #[inline]
+                fn saturating_from(value: $src) -> Self {
+                    value as $dst
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_float!(f32, f64);
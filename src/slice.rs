@@ -0,0 +1,30 @@
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::iter::FromIterator;
+
+use crate::{IndexedError, IteratorTo};
+
+/// Extension trait providing the [`try_to_all`](SliceTo::try_to_all) method on slices.
+pub trait SliceTo<S> {
+    /// Tries to convert a clone of every element to the item type of `C` by calling
+    /// `TryInto::try_into` and collects the results into `C`.
+    ///
+    /// Unlike [`try_collect_to`](IteratorTo::try_collect_to), this doesn't stop at
+    /// the first failure, but returns the errors of all elements that failed to convert.
+    #[allow(clippy::type_complexity)]
+    fn try_to_all<C>(&self) -> Result<C, Vec<IndexedError<<S as TryInto<C::Item>>::Error>>>
+    where
+        S: Clone + TryInto<C::Item>,
+        C: IntoIterator + FromIterator<C::Item>;
+}
+
+impl<S> SliceTo<S> for [S] {
+    #[inline]
+    fn try_to_all<C>(&self) -> Result<C, Vec<IndexedError<<S as TryInto<C::Item>>::Error>>>
+    where
+        S: Clone + TryInto<C::Item>,
+        C: IntoIterator + FromIterator<C::Item>,
+    {
+        self.iter().cloned().try_to_all()
+    }
+}
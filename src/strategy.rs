@@ -0,0 +1,82 @@
+//! Zero-sized conversion strategies for [`cast_with`](crate::To::cast_with).
+//!
+//! Each strategy selects one of the conversion traits of this crate, which lets
+//! generic code take the conversion policy as a type parameter.
+
+use core::convert::TryInto;
+
+use crate::{ExactError, LossyFrom, SaturatingFrom, TryFromExact, WrappingFrom};
+
+/// A conversion policy from `Src` to `Dst`.
+pub trait CastStrategy<Src, Dst> {
+    /// The result of the conversion, either `Dst` itself or a `Result` wrapping it.
+    type Output;
+
+    /// Converts `value` according to this strategy.
+    fn cast(value: Src) -> Self::Output;
+}
+
+/// Converts using [`TryInto`], like [`try_to`](crate::To::try_to).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Checked;
+
+/// Converts using [`SaturatingFrom`], like [`to_saturating`](crate::To::to_saturating).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Saturating;
+
+/// Converts using [`WrappingFrom`], like [`to_wrapping`](crate::To::to_wrapping).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Wrapping;
+
+/// Converts using [`TryFromExact`], like [`try_to_exact`](crate::To::try_to_exact).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Exact;
+
+/// Converts using [`LossyFrom`], like [`cast`](crate::To::cast).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Lossy;
+
+impl<Src: TryInto<Dst>, Dst> CastStrategy<Src, Dst> for Checked {
+    type Output = Result<Dst, Src::Error>;
+
+    #[inline(always)]
+    fn cast(value: Src) -> Self::Output {
+        value.try_into()
+    }
+}
+
+impl<Src, Dst: SaturatingFrom<Src>> CastStrategy<Src, Dst> for Saturating {
+    type Output = Dst;
+
+    #[inline(always)]
+    fn cast(value: Src) -> Self::Output {
+        Dst::saturating_from(value)
+    }
+}
+
+impl<Src, Dst: WrappingFrom<Src>> CastStrategy<Src, Dst> for Wrapping {
+    type Output = Dst;
+
+    #[inline(always)]
+    fn cast(value: Src) -> Self::Output {
+        Dst::wrapping_from(value)
+    }
+}
+
+impl<Src, Dst: TryFromExact<Src>> CastStrategy<Src, Dst> for Exact {
+    type Output = Result<Dst, ExactError>;
+
+    #[inline(always)]
+    fn cast(value: Src) -> Self::Output {
+        Dst::try_from_exact(value)
+    }
+}
+
+impl<Src, Dst: LossyFrom<Src>> CastStrategy<Src, Dst> for Lossy {
+    type Output = Dst;
+
+    #[inline(always)]
+    fn cast(value: Src) -> Self::Output {
+        Dst::lossy_from(value)
+    }
+}
@@ -0,0 +1,181 @@
+use core::convert::{Infallible, TryInto};
+use core::fmt;
+
+/// Element-wise conversion between tuples, converting each element with `Into`.
+///
+/// Implemented for tuples of arity 1 to 12.
+pub trait TupleFrom<T>: Sized {
+    /// Converts each element of `value`.
+    fn tuple_from(value: T) -> Self;
+}
+
+/// Element-wise fallible conversion between tuples, converting each element with `TryInto`.
+///
+/// Implemented for tuples of arity 1 to 12.
+pub trait TryTupleFrom<T>: Sized {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Tries to convert each element of `value`, stopping at the first failure.
+    fn try_tuple_from(value: T) -> Result<Self, Self::Error>;
+}
+
+/// Extension trait providing the [`tuple_to`](TupleTo::tuple_to) and
+/// [`try_tuple_to`](TupleTo::try_tuple_to) methods.
+pub trait TupleTo {
+    /// Converts each element to the corresponding element of `T` by calling `Into::into`.
+    #[inline(always)]
+    fn tuple_to<T>(self) -> T
+    where
+        Self: Sized,
+        T: TupleFrom<Self>,
+    {
+        T::tuple_from(self)
+    }
+
+    /// Tries to convert each element to the corresponding element of `T` by calling
+    /// `TryInto::try_into`.
+    ///
+    /// The returned [`TupleError`] says which position failed.
+    #[inline(always)]
+    fn try_tuple_to<T>(self) -> Result<T, <T as TryTupleFrom<Self>>::Error>
+    where
+        Self: Sized,
+        T: TryTupleFrom<Self>,
+    {
+        T::try_tuple_from(self)
+    }
+}
+
+/// Blanket impl for all types.
+/// This makes sure that everything implements `TupleTo` and
+/// that no downstream impls can exist.
+impl<T: ?Sized> TupleTo for T {}
+
+/// The error type returned by [`try_tuple_to`](TupleTo::try_tuple_to), holding the
+/// error of the element that failed to convert.
+///
+/// The variant says which position failed. Unused type parameters default to `Infallible`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TupleError<
+    E0,
+    E1 = Infallible,
+    E2 = Infallible,
+    E3 = Infallible,
+    E4 = Infallible,
+    E5 = Infallible,
+    E6 = Infallible,
+    E7 = Infallible,
+    E8 = Infallible,
+    E9 = Infallible,
+    E10 = Infallible,
+    E11 = Infallible,
+> {
+    /// The first element failed to convert.
+    At0(E0),
+    /// The second element failed to convert.
+    At1(E1),
+    /// The third element failed to convert.
+    At2(E2),
+    /// The fourth element failed to convert.
+    At3(E3),
+    /// The fifth element failed to convert.
+    At4(E4),
+    /// The sixth element failed to convert.
+    At5(E5),
+    /// The seventh element failed to convert.
+    At6(E6),
+    /// The eighth element failed to convert.
+    At7(E7),
+    /// The ninth element failed to convert.
+    At8(E8),
+    /// The tenth element failed to convert.
+    At9(E9),
+    /// The eleventh element failed to convert.
+    At10(E10),
+    /// The twelfth element failed to convert.
+    At11(E11),
+}
+
+macro_rules! tuple_error_impls {
+    ($($index:tt $variant:ident $err:ident),*) => {
+        impl<$($err),*> TupleError<$($err),*> {
+            /// Returns the position of the element that failed to convert.
+            pub fn position(&self) -> usize {
+                match self {
+                    $(TupleError::$variant(_) => $index,)*
+                }
+            }
+        }
+
+        impl<$($err: fmt::Display),*> fmt::Display for TupleError<$($err),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(TupleError::$variant(err) => write!(f, "element {}: {}", $index, err),)*
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($err: std::error::Error + 'static),*> std::error::Error for TupleError<$($err),*> {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    $(TupleError::$variant(err) => Some(err),)*
+                }
+            }
+        }
+    };
+}
+
+tuple_error_impls!(
+    0 At0 E0, 1 At1 E1, 2 At2 E2, 3 At3 E3, 4 At4 E4, 5 At5 E5,
+    6 At6 E6, 7 At7 E7, 8 At8 E8, 9 At9 E9, 10 At10 E10, 11 At11 E11
+);
+
+macro_rules! tuple_impls {
+    ($(($($index:tt $variant:ident $src:ident $dst:ident),+))+) => {
+        $(
+            impl<$($src, $dst),+> TupleFrom<($($src,)+)> for ($($dst,)+)
+            where
+                $($src: Into<$dst>),+
+            {
+                #[inline]
+                fn tuple_from(value: ($($src,)+)) -> Self {
+                    ($(value.$index.into(),)+)
+                }
+            }
+
+            impl<$($src, $dst),+> TryTupleFrom<($($src,)+)> for ($($dst,)+)
+            where
+                $($src: TryInto<$dst>),+
+            {
+                type Error = TupleError<$(<$src as TryInto<$dst>>::Error),+>;
+
+                #[inline]
+                fn try_tuple_from(value: ($($src,)+)) -> Result<Self, Self::Error> {
+                    Ok(($(value.$index.try_into().map_err(TupleError::$variant)?,)+))
+                }
+            }
+        )+
+    };
+}
+
+tuple_impls! {
+    (0 At0 S0 T0)
+    (0 At0 S0 T0, 1 At1 S1 T1)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3, 4 At4 S4 T4)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3, 4 At4 S4 T4, 5 At5 S5 T5)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3, 4 At4 S4 T4, 5 At5 S5 T5, 6 At6 S6 T6)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3, 4 At4 S4 T4, 5 At5 S5 T5, 6 At6 S6 T6,
+     7 At7 S7 T7)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3, 4 At4 S4 T4, 5 At5 S5 T5, 6 At6 S6 T6,
+     7 At7 S7 T7, 8 At8 S8 T8)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3, 4 At4 S4 T4, 5 At5 S5 T5, 6 At6 S6 T6,
+     7 At7 S7 T7, 8 At8 S8 T8, 9 At9 S9 T9)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3, 4 At4 S4 T4, 5 At5 S5 T5, 6 At6 S6 T6,
+     7 At7 S7 T7, 8 At8 S8 T8, 9 At9 S9 T9, 10 At10 S10 T10)
+    (0 At0 S0 T0, 1 At1 S1 T1, 2 At2 S2 T2, 3 At3 S3 T3, 4 At4 S4 T4, 5 At5 S5 T5, 6 At6 S6 T6,
+     7 At7 S7 T7, 8 At8 S8 T8, 9 At9 S9 T9, 10 At10 S10 T10, 11 At11 S11 T11)
+}
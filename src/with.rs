@@ -0,0 +1,69 @@
+use core::convert::TryFrom;
+
+/// Conversion that needs some external context, like a unit scale or an interner.
+///
+/// Every `From` impl is also available with the empty context `()`.
+pub trait FromWith<T, C: ?Sized>: Sized {
+    /// Converts `value` using `ctx`.
+    fn from_with(value: T, ctx: &C) -> Self;
+}
+
+impl<T, U: From<T>> FromWith<T, ()> for U {
+    #[inline(always)]
+    fn from_with(value: T, _: &()) -> Self {
+        U::from(value)
+    }
+}
+
+/// Fallible conversion that needs some external context, like a lookup table.
+///
+/// Every `TryFrom` impl is also available with the empty context `()`.
+pub trait TryFromWith<T, C: ?Sized>: Sized {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// Tries to convert `value` using `ctx`.
+    fn try_from_with(value: T, ctx: &C) -> Result<Self, Self::Error>;
+}
+
+impl<T, U: TryFrom<T>> TryFromWith<T, ()> for U {
+    type Error = U::Error;
+
+    #[inline(always)]
+    fn try_from_with(value: T, _: &()) -> Result<Self, Self::Error> {
+        U::try_from(value)
+    }
+}
+
+/// Extension trait providing the [`to_with`](ToWith::to_with) and
+/// [`try_to_with`](ToWith::try_to_with) methods.
+///
+/// The context type `C` is inferred from the argument, so only the target type
+/// has to be named, as in `x.to_with::<T>(&ctx)`.
+pub trait ToWith<C: ?Sized> {
+    /// Converts to `T` using the context `ctx` by calling `FromWith<Self, C>::from_with`.
+    #[inline(always)]
+    fn to_with<T>(self, ctx: &C) -> T
+    where
+        Self: Sized,
+        T: FromWith<Self, C>,
+    {
+        T::from_with(self, ctx)
+    }
+
+    /// Tries to convert to `T` using the context `ctx` by calling
+    /// `TryFromWith<Self, C>::try_from_with`.
+    #[inline(always)]
+    fn try_to_with<T>(self, ctx: &C) -> Result<T, <T as TryFromWith<Self, C>>::Error>
+    where
+        Self: Sized,
+        T: TryFromWith<Self, C>,
+    {
+        T::try_from_with(self, ctx)
+    }
+}
+
+/// Blanket impl for all types and contexts.
+/// This makes sure that everything implements `ToWith` and
+/// that no downstream impls can exist.
+impl<T: ?Sized, C: ?Sized> ToWith<C> for T {}
@@ -0,0 +1,28 @@
+/// Conversion that truncates integers using two's-complement wrapping.
+///
+/// Implemented for every pair of primitive integer types. Behaves exactly
+/// like an `as` cast between integers.
+pub trait WrappingFrom<T>: Sized {
+    /// Converts `value`, keeping only as many low bits as fit into `Self`.
+    fn wrapping_from(value: T) -> Self;
+}
+
+macro_rules! impl_wrapping {
+    ($($src:ty),*) => {
+        $(
+            impl_wrapping!(@ $src => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+        )*
+    };
+    (@ $src:ty => $($dst:ty),*) => {
+        $(
+            impl WrappingFrom<$src> for $dst {
+                #[inline]
+                fn wrapping_from(value: $src) -> Self {
+                    value as $dst
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,572 @@
+//! Derive macros for the [`to_method`](https://docs.rs/to_method) crate.
+//!
+//! Don't depend on this crate directly, enable the `derive` feature of
+//! `to_method` instead, which re-exports everything in here.
+//!
+//! # Newtypes
+//!
+//! [`FromInner`](derive@FromInner) and [`IntoInner`](derive@IntoInner) generate
+//! `From` impls between a single-field struct and its field, so that
+//! [`To::to`](https://docs.rs/to_method/*/to_method/trait.To.html#method.to)
+//! works in both directions:
+//!
+//! ```
+//! use to_method::{FromInner, IntoInner, To as _};
+//!
+//! #[derive(FromInner, IntoInner)]
+//! struct Meters(f64);
+//!
+//! let m = 5.0.to::<Meters>();
+//! assert_eq!(m.to::<f64>(), 5.0);
+//! ```
+//!
+//! The `#[to(from = "A")]` and `#[to(into = "B")]` attributes additionally
+//! generate conversions that go through the inner type:
+//!
+//! ```
+//! use to_method::{FromInner, IntoInner, To as _};
+//!
+//! #[derive(FromInner, IntoInner)]
+//! #[to(from = "u8", from = "u16", into = "i64")]
+//! struct Id(u32);
+//!
+//! let id = 7_u8.to::<Id>();
+//! assert_eq!(id.to::<i64>(), 7);
+//! ```
+//!
+//! On generic newtypes, these conversions only exist where the inner type supports them:
+//!
+//! ```
+//! use std::ffi::CString;
+//! use std::num::NonZeroU8;
+//! use to_method::{FromInner, IntoInner, To as _};
+//!
+//! #[derive(FromInner, IntoInner)]
+//! #[to(from = "&'static str", into = "CString")]
+//! struct Bytes<T>(Vec<T>);
+//!
+//! let bytes = "hi".to::<Bytes<u8>>();
+//! assert_eq!(bytes.0, b"hi");
+//!
+//! let one = NonZeroU8::new(1).unwrap();
+//! assert_eq!(Bytes(vec![one]).to::<CString>().as_bytes(), [1]);
+//! ```
+//!
+//! # Fieldless enums
+//!
+//! [`TryFromRepr`](derive@TryFromRepr) and [`IntoRepr`](derive@IntoRepr) generate
+//! conversions between a fieldless enum and the integer type given in its
+//! `#[repr(...)]` attribute:
+//!
+//! ```
+//! use to_method::{InvalidDiscriminant, IntoRepr, To as _, TryFromRepr};
+//!
+//! #[derive(Debug, PartialEq, TryFromRepr, IntoRepr)]
+//! #[repr(u8)]
+//! enum Opcode {
+//!     Nop,
+//!     Load = 0x10,
+//!     Store,
+//! }
+//!
+//! assert_eq!(0x11_u8.try_to::<Opcode>(), Ok(Opcode::Store));
+//! assert_eq!(0x12_u8.try_to::<Opcode>(), Err(InvalidDiscriminant { value: 0x12 }));
+//! assert_eq!(Opcode::Load.to::<u8>(), 0x10);
+//! ```
+//!
+//! # Field-wise struct conversions
+//!
+//! [`ConvertFrom`](derive@ConvertFrom) converts between structs that have the
+//! same field names, converting each field on its own:
+//!
+//! ```
+//! use core::num::TryFromIntError;
+//! use to_method::{ConvertFrom, FieldError, To as _};
+//!
+//! struct ApiUser {
+//!     id: i64,
+//!     name: String,
+//! }
+//!
+//! struct DbUser {
+//!     id: u32,
+//!     name: Box<str>,
+//! }
+//!
+//! #[derive(ConvertFrom)]
+//! #[convert(from = "DbUser")]
+//! #[convert(try_from = "ApiUser", error = "TryFromIntError")]
+//! struct User {
+//!     id: u32,
+//!     name: String,
+//! }
+//!
+//! let user = ApiUser { id: 7, name: "Ann".into() }.try_to::<User>().unwrap();
+//! assert_eq!(user.id, 7);
+//!
+//! let err = ApiUser { id: -1, name: "Bob".into() }.try_to::<User>().err().unwrap();
+//! assert_eq!(err.field, "id");
+//! ```
+//!
+//! `TryFromIntError` happens to implement `From<Infallible>`, which is the error
+//! type of fields that always convert. For other error types, mark those fields
+//! with `#[convert(into)]`:
+//!
+//! ```
+//! use core::convert::TryFrom;
+//! use to_method::{ConvertFrom, To as _};
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct NegativeAge;
+//!
+//! struct Age(u8);
+//!
+//! impl TryFrom<i32> for Age {
+//!     type Error = NegativeAge;
+//!
+//!     fn try_from(value: i32) -> Result<Self, NegativeAge> {
+//!         u8::try_from(value).map(Age).map_err(|_| NegativeAge)
+//!     }
+//! }
+//!
+//! struct Form {
+//!     name: String,
+//!     age: i32,
+//! }
+//!
+//! #[derive(ConvertFrom)]
+//! #[convert(try_from = "Form", error = "NegativeAge")]
+//! struct Person {
+//!     #[convert(into)]
+//!     name: String,
+//!     age: Age,
+//! }
+//!
+//! let person = Form { name: "Ann".into(), age: 30 }.try_to::<Person>().unwrap();
+//! assert_eq!(person.age.0, 30);
+//!
+//! let err = Form { name: "Bob".into(), age: -1 }.try_to::<Person>().err().unwrap();
+//! assert_eq!((err.field, err.error), ("age", NegativeAge));
+//! ```
+
+#![forbid(missing_docs)]
+#![forbid(unsafe_code)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, Field, Fields, Generics, Ident,
+    LitStr, Member, Type, WherePredicate,
+};
+
+/// Derives `From<Inner>` for a single-field struct.
+///
+/// Every `#[to(from = "A")]` attribute also derives `From<A>`, converting via `Inner`.
+/// `#[to(into = "B")]` keys are left to [`IntoInner`](derive@IntoInner).
+#[proc_macro_derive(FromInner, attributes(to))]
+pub fn derive_from_inner(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_inner(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `From<Self>` for the type of the field of a single-field struct.
+///
+/// Every `#[to(into = "B")]` attribute also derives `From<Self>` for `B`, converting via the field.
+/// `#[to(from = "A")]` keys are left to [`FromInner`](derive@FromInner).
+///
+/// The orphan rules forbid this when the field type is a bare type parameter.
+#[proc_macro_derive(IntoInner, attributes(to))]
+pub fn derive_into_inner(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_into_inner(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `TryFrom<R>` for a fieldless enum with a `#[repr(R)]` attribute.
+///
+/// The error type is `to_method::InvalidDiscriminant<R>`.
+#[proc_macro_derive(TryFromRepr)]
+pub fn derive_try_from_repr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_try_from_repr(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `From<Self>` for the integer type `R` of a fieldless enum with a `#[repr(R)]` attribute.
+#[proc_macro_derive(IntoRepr)]
+pub fn derive_into_repr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_into_repr(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives field-wise conversions from other structs with the same field names.
+///
+/// `#[convert(from = "T")]` derives `From<T>`, converting each field with `Into`.
+///
+/// `#[convert(try_from = "T", error = "E")]` derives `TryFrom<T>`, converting each
+/// field with `TryInto`. Its error type is `to_method::FieldError<E>`, which names
+/// the field that failed. The error of every field conversion has to implement
+/// `Into<E>`. Fields that always convert have `Infallible` as their error, which
+/// only converts into a custom `E` if it has a `From<Infallible>` impl. Mark such
+/// fields with `#[convert(into)]` to convert them with `Into` instead.
+///
+/// Despite sharing its name, this derive doesn't implement the `to_method::ConvertFrom`
+/// trait. The generated `From` impls reach it through its `ViaFrom` marker anyway.
+#[proc_macro_derive(ConvertFrom, attributes(convert))]
+pub fn derive_convert_from(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_convert_from(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The conversions requested with `#[to(...)]` attributes.
+#[derive(Default)]
+struct ToAttrs {
+    from: Vec<Type>,
+    into: Vec<Type>,
+}
+
+fn parse_to_attrs(input: &DeriveInput) -> syn::Result<ToAttrs> {
+    let mut attrs = ToAttrs::default();
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("to")) {
+        attr.parse_nested_meta(|meta| {
+            let list = if meta.path.is_ident("from") {
+                &mut attrs.from
+            } else if meta.path.is_ident("into") {
+                &mut attrs.into
+            } else {
+                return Err(meta.error("expected `from` or `into`"));
+            };
+            list.push(meta.value()?.parse::<LitStr>()?.parse()?);
+            Ok(())
+        })?;
+    }
+    Ok(attrs)
+}
+
+/// Returns a copy of `generics` with `predicate` added to its where clause.
+fn with_predicate(generics: &Generics, predicate: WherePredicate) -> Generics {
+    let mut generics = generics.clone();
+    generics.make_where_clause().predicates.push(predicate);
+    generics
+}
+
+/// Returns the type of the only field of a struct and how to access it.
+fn single_field(input: &DeriveInput) -> syn::Result<(&Type, Member)> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(input, "expected a struct")),
+    };
+    let field = match fields {
+        Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "expected a struct with exactly one field",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    Ok((&field.ty, member))
+}
+
+fn expand_from_inner(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (inner, member) = single_field(input)?;
+    let attrs = parse_to_attrs(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let from = attrs.from.iter().map(|source| {
+        let generics = with_predicate(
+            &input.generics,
+            parse_quote!(#inner: ::core::convert::From<#source>),
+        );
+        let where_clause = &generics.where_clause;
+        quote! {
+            impl #impl_generics ::core::convert::From<#source> for #name #ty_generics #where_clause {
+                #[inline]
+                fn from(value: #source) -> Self {
+                    Self { #member: <#inner as ::core::convert::From<#source>>::from(value) }
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#inner> for #name #ty_generics #where_clause {
+            #[inline]
+            fn from(value: #inner) -> Self {
+                Self { #member: value }
+            }
+        }
+
+        #(#from)*
+    })
+}
+
+fn expand_into_inner(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (inner, member) = single_field(input)?;
+    let attrs = parse_to_attrs(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let into = attrs.into.iter().map(|target| {
+        let generics = with_predicate(
+            &input.generics,
+            parse_quote!(#target: ::core::convert::From<#inner>),
+        );
+        let where_clause = &generics.where_clause;
+        quote! {
+            impl #impl_generics ::core::convert::From<#name #ty_generics> for #target #where_clause {
+                #[inline]
+                fn from(value: #name #ty_generics) -> Self {
+                    <#target as ::core::convert::From<#inner>>::from(value.#member)
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #inner #where_clause {
+            #[inline]
+            fn from(value: #name #ty_generics) -> Self {
+                value.#member
+            }
+        }
+
+        #(#into)*
+    })
+}
+
+/// Returns the variants of a fieldless enum and the integer type of its `#[repr(...)]`.
+fn fieldless_enum(input: &DeriveInput) -> syn::Result<(&DataEnum, Ident)> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => return Err(syn::Error::new_spanned(input, "expected an enum")),
+    };
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "expected a fieldless enum",
+        ));
+    }
+
+    let mut repr = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            const INTS: &[&str] = &[
+                "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+                "isize",
+            ];
+            if let Some(ident) = meta
+                .path
+                .get_ident()
+                .filter(|i| INTS.iter().any(|int| i == int))
+            {
+                repr = Some(ident.clone());
+            }
+            Ok(())
+        })?;
+    }
+    let repr = repr.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "expected a `#[repr(...)]` attribute with a primitive integer type",
+        )
+    })?;
+    Ok((data, repr))
+}
+
+fn expand_try_from_repr(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (data, repr) = fieldless_enum(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let variants = data.variants.iter().map(|variant| &variant.ident);
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<#repr> for #name #ty_generics #where_clause {
+            type Error = ::to_method::InvalidDiscriminant<#repr>;
+
+            fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
+                #(
+                    if value == Self::#variants as #repr {
+                        return ::core::result::Result::Ok(Self::#variants);
+                    }
+                )*
+                ::core::result::Result::Err(::to_method::InvalidDiscriminant { value })
+            }
+        }
+    })
+}
+
+fn expand_into_repr(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (_, repr) = fieldless_enum(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #repr #where_clause {
+            #[inline]
+            fn from(value: #name #ty_generics) -> Self {
+                value as #repr
+            }
+        }
+    })
+}
+
+/// A conversion requested with a `#[convert(...)]` attribute.
+struct Convert {
+    source: Type,
+    /// The error type for `try_from`, or `None` for `from`.
+    error: Option<Type>,
+}
+
+fn parse_convert_attrs(input: &DeriveInput) -> syn::Result<Vec<Convert>> {
+    let mut converts = Vec::new();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("convert"))
+    {
+        let mut from = None;
+        let mut try_from = None;
+        let mut error = None;
+        attr.parse_nested_meta(|meta| {
+            let slot = if meta.path.is_ident("from") {
+                &mut from
+            } else if meta.path.is_ident("try_from") {
+                &mut try_from
+            } else if meta.path.is_ident("error") {
+                &mut error
+            } else {
+                return Err(meta.error("expected `from`, `try_from` or `error`"));
+            };
+            if slot.is_some() {
+                return Err(meta.error("duplicate key"));
+            }
+            *slot = Some(meta.value()?.parse::<LitStr>()?.parse::<Type>()?);
+            Ok(())
+        })?;
+        converts.push(match (from, try_from, error) {
+            (Some(source), None, None) => Convert {
+                source,
+                error: None,
+            },
+            (None, Some(source), Some(error)) => Convert {
+                source,
+                error: Some(error),
+            },
+            (None, Some(_), None) => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`try_from` requires an `error` type",
+                ))
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "expected either `from = \"...\"` or `try_from = \"...\", error = \"...\"`",
+                ))
+            }
+        });
+    }
+    Ok(converts)
+}
+
+/// Returns whether a field is marked with `#[convert(into)]`.
+fn is_convert_into(field: &Field) -> syn::Result<bool> {
+    let mut into = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("convert"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("into") {
+                into = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `into`"))
+            }
+        })?;
+    }
+    Ok(into)
+}
+
+fn expand_convert_from(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(input, "expected a struct")),
+    };
+    let members: Vec<Member> = fields.members().collect();
+    let into: Vec<bool> = fields
+        .iter()
+        .map(is_convert_into)
+        .collect::<syn::Result<_>>()?;
+    let names: Vec<String> = members
+        .iter()
+        .map(|member| match member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        })
+        .collect();
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let impls = parse_convert_attrs(input)?.into_iter().map(|Convert { source, error }| match error {
+        None => quote! {
+            impl #impl_generics ::core::convert::From<#source> for #name #ty_generics #where_clause {
+                fn from(source: #source) -> Self {
+                    Self {
+                        #( #members: ::core::convert::Into::into(source.#members), )*
+                    }
+                }
+            }
+        },
+        Some(error) => {
+            let values = members.iter().zip(&names).zip(&into).map(|((member, name), into)| {
+                if *into {
+                    quote! { ::core::convert::Into::into(source.#member) }
+                } else {
+                    quote! {
+                        ::core::convert::TryInto::try_into(source.#member).map_err(
+                            |error| ::to_method::FieldError {
+                                field: #name,
+                                error: ::core::convert::Into::into(error),
+                            },
+                        )?
+                    }
+                }
+            });
+            quote! {
+                impl #impl_generics ::core::convert::TryFrom<#source> for #name #ty_generics #where_clause {
+                    type Error = ::to_method::FieldError<#error>;
+
+                    fn try_from(source: #source) -> ::core::result::Result<Self, Self::Error> {
+                        ::core::result::Result::Ok(Self {
+                            #( #members: #values, )*
+                        })
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(quote! { #(#impls)* })
+}